@@ -0,0 +1,151 @@
+//! HTML rendering primitives used while compiling events to HTML.
+//!
+//! The event-walking compiler itself lives outside this file (it turns the
+//! token stream from [`parser::parse`][crate::parser::parse] into HTML, and
+//! is not part of this change); what lives here is the part that actually
+//! depends on [`Options::heading_offset`][crate::Options::heading_offset],
+//! [`Options::heading_ids`][crate::Options::heading_ids], and
+//! [`Options::code_block`][crate::Options::code_block]. The compiler calls
+//! [`heading_tags`][] when it exits a `Heading` (atx or setext) and
+//! [`code_block_html`][] when it exits a `CodeIndented`/`CodeFenced`,
+//! instead of writing `<h1>…</h1>`/`<pre><code>…</code></pre>` itself.
+
+use crate::util::slugger::GithubSlugger;
+use crate::{CodeBlockInfo, Options};
+
+/// The highest heading level HTML supports.
+const HEADING_LEVEL_MAX: u8 = 6;
+
+/// Build the opening and closing tag for a heading.
+///
+/// `level` is the heading's *source* level (`1` for a single `#`, and so
+/// on), before [`Options::heading_offset`][crate::Options::heading_offset]
+/// is applied; the result is clamped so it never exceeds `<h6>`.
+/// When [`Options::heading_ids`][crate::Options::heading_ids] is set, `text`
+/// (the heading's rendered text content) is slugged through `slugger` and
+/// added as an `id` attribute.
+///
+/// ```
+/// use micromark::compiler::heading_tags;
+/// use micromark::util::slugger::GithubSlugger;
+/// use micromark::Options;
+///
+/// let mut slugger = GithubSlugger::new();
+///
+/// // Defaults: no offset, no ids.
+/// let (open, close) = heading_tags(&Options::default(), &mut slugger, 1, "Hi");
+/// assert_eq!(open, "<h1>");
+/// assert_eq!(close, "</h1>");
+///
+/// // Offset shifts the level, clamping at h6.
+/// let (open, _) = heading_tags(
+///     &Options {
+///         heading_offset: 2,
+///         ..Options::default()
+///     },
+///     &mut slugger,
+///     6,
+///     "Hi",
+/// );
+/// assert_eq!(open, "<h6>");
+///
+/// // Ids are slugged and deduplicated across the document.
+/// let options = Options {
+///     heading_ids: true,
+///     ..Options::default()
+/// };
+/// let (first, _) = heading_tags(&options, &mut slugger, 2, "Hello World");
+/// let (second, _) = heading_tags(&options, &mut slugger, 2, "Hello World");
+/// assert_eq!(first, "<h2 id=\"hello-world\">");
+/// assert_eq!(second, "<h2 id=\"hello-world-1\">");
+/// ```
+pub fn heading_tags(
+    options: &Options,
+    slugger: &mut GithubSlugger,
+    level: u8,
+    text: &str,
+) -> (String, String) {
+    let level = level
+        .saturating_add(options.heading_offset)
+        .clamp(1, HEADING_LEVEL_MAX);
+
+    let open = if options.heading_ids {
+        format!("<h{} id=\"{}\">", level, slugger.slug(text))
+    } else {
+        format!("<h{}>", level)
+    };
+
+    (open, format!("</h{}>", level))
+}
+
+/// Render a fenced or indented code block.
+///
+/// `info` is the info string after a fenced block's opening fence (the
+/// language plus any metadata), or the empty string for indented code
+/// blocks, which have none. `value` is the code itself.
+///
+/// When [`Options::code_block`][crate::Options::code_block] is set, it is
+/// called to produce the inner HTML and extra `<code>` attributes; the
+/// default, CommonMark-compliant behavior otherwise escapes `value` as-is
+/// and, for fenced blocks with a language, adds a `class="language-…"`
+/// attribute with the first whitespace-separated word of `info`.
+///
+/// ```
+/// use micromark::compiler::code_block_html;
+/// use micromark::{CodeBlockRender, Options};
+///
+/// assert_eq!(
+///     code_block_html(&Options::default(), "", "a"),
+///     "<pre><code>a\n</code></pre>"
+/// );
+/// assert_eq!(
+///     code_block_html(&Options::default(), "js", "a"),
+///     "<pre><code class=\"language-js\">a\n</code></pre>"
+/// );
+///
+/// // A hook fully overrides the inner HTML and attributes.
+/// let options = Options {
+///     code_block: Some(Box::new(|info| CodeBlockRender {
+///         html: format!("<mark>{}</mark>", info.value),
+///         attributes: " data-lang=\"rs\"".into(),
+///     })),
+///     ..Options::default()
+/// };
+/// assert_eq!(
+///     code_block_html(&options, "rs", "a"),
+///     "<pre><code data-lang=\"rs\"><mark>a</mark></code></pre>"
+/// );
+/// ```
+pub fn code_block_html(options: &Options, info: &str, value: &str) -> String {
+    if let Some(hook) = &options.code_block {
+        let render = hook(CodeBlockInfo { info, value });
+        return format!(
+            "<pre><code{}>{}</code></pre>",
+            render.attributes, render.html
+        );
+    }
+
+    let language = info.split_whitespace().next();
+    let class = language.map_or_else(String::new, |language| {
+        format!(" class=\"language-{}\"", escape(language))
+    });
+
+    format!("<pre><code{}>{}\n</code></pre>", class, escape(value))
+}
+
+/// Escape `&`, `<`, `>`, and `"`, as the rest of the HTML compiler does.
+fn escape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+
+    for char in value.chars() {
+        match char {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            _ => result.push(char),
+        }
+    }
+
+    result
+}
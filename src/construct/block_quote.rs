@@ -0,0 +1,120 @@
+//! Block quote is a construct that occurs in the [document][] content type.
+//!
+//! It forms with the following BNF:
+//!
+//! ```bnf
+//! block_quote_start ::= block_quote_prefix
+//! block_quote_cont ::= block_quote_prefix
+//!
+//! block_quote_prefix ::= 0*3space_or_tab '>' [ space_or_tab ]
+//! ```
+//!
+//! Block quote is one of the two containers in markdown: unlike the other
+//! constructs in flow, it is parsed line by line from the [document][]
+//! content type, which decides, for each line, whether the quote continues
+//! (this module), before handing the rest of the line to [flow][].
+//!
+//! ## Tokens
+//!
+//! *   [`BlockQuote`][Token::BlockQuote]
+//! *   [`BlockQuotePrefix`][Token::BlockQuotePrefix]
+//! *   [`BlockQuoteMarker`][Token::BlockQuoteMarker]
+//!
+//! ## References
+//!
+//! *   [`block-quote.js` in `micromark`](https://github.com/micromark/micromark/blob/main/packages/micromark-core-commonmark/dev/lib/block-quote.js)
+//! *   [*§ 5.1 Block quotes* in `CommonMark`](https://spec.commonmark.org/0.30/#block-quotes)
+//!
+//! [document]: crate::content::document
+//! [flow]: crate::content::flow
+
+use crate::construct::partial_space_or_tab::space_or_tab_min_max;
+use crate::token::Token;
+use crate::tokenizer::{State, StateName, Tokenizer};
+
+/// The maximum number of spaces (or tabs) allowed before the `>` marker.
+const BLOCK_QUOTE_MARKER_INDENT_MAX: usize = 3;
+
+/// Start of block quote.
+///
+/// Used to open a new block quote.
+///
+/// ```markdown
+/// > | > a
+///     ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.constructs.block_quote {
+        tokenizer.enter(Token::BlockQuote);
+        cont(tokenizer)
+    } else {
+        State::Nok
+    }
+}
+
+/// Continuation of block quote.
+///
+/// Used on every line after the first, to check whether the quote keeps
+/// going.
+///
+/// ```markdown
+///   | > a
+/// > | > b
+///     ^
+/// ```
+pub fn continuation(tokenizer: &mut Tokenizer) -> State {
+    cont(tokenizer)
+}
+
+/// Before the `>` marker, after up to [`BLOCK_QUOTE_MARKER_INDENT_MAX`][]
+/// spaces or tabs.
+fn cont(tokenizer: &mut Tokenizer) -> State {
+    let state_name = space_or_tab_min_max(tokenizer, 0, BLOCK_QUOTE_MARKER_INDENT_MAX);
+    tokenizer.attempt_opt(state_name, StateName::BlockQuoteBefore)
+}
+
+/// At the `>` marker.
+///
+/// ```markdown
+/// > | > a
+///     ^
+/// ```
+pub fn before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'>') => {
+            tokenizer.enter(Token::BlockQuotePrefix);
+            tokenizer.enter(Token::BlockQuoteMarker);
+            tokenizer.consume();
+            tokenizer.exit(Token::BlockQuoteMarker);
+            State::Fn(StateName::BlockQuoteMarkerAfter)
+        }
+        _ => State::Nok,
+    }
+}
+
+/// After the `>` marker, before an optional single space or tab.
+///
+/// ```markdown
+/// > | > a
+///      ^
+/// ```
+pub fn marker_after(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b' ' | b'\t') => {
+            let state_name = space_or_tab_min_max(tokenizer, 1, 1);
+            tokenizer.attempt_opt(state_name, StateName::BlockQuotePrefixEnd)
+        }
+        _ => prefix_end(tokenizer),
+    }
+}
+
+/// After the block quote prefix.
+///
+/// ```markdown
+/// > | > a
+///       ^
+/// ```
+pub fn prefix_end(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.exit(Token::BlockQuotePrefix);
+    State::Ok
+}
@@ -0,0 +1,182 @@
+//! List item is a construct that occurs in the [document][] content type.
+//!
+//! It forms with the following BNF:
+//!
+//! ```bnf
+//! list_item ::= list_item_prefix *line
+//!
+//! list_item_prefix ::= 0*3space_or_tab list_item_marker [ 1*4space_or_tab ]
+//! list_item_marker ::= '*' | '+' | '-' | 1*9digit ('.' | ')')
+//! ```
+//!
+//! Like [block quote][block_quote], list item is a container: [document][]
+//! asks it, line by line, whether it continues, before handing the rest of
+//! the line to [flow][].
+//! A list item continues as long as the following line is indented as far
+//! as the item's content (the width computed in [`prefix_end`][]).
+//!
+//! ## Tokens
+//!
+//! *   [`ListOrdered`][Token::ListOrdered]
+//! *   [`ListUnordered`][Token::ListUnordered]
+//! *   [`ListItem`][Token::ListItem]
+//! *   [`ListItemPrefix`][Token::ListItemPrefix]
+//! *   [`ListItemMarker`][Token::ListItemMarker]
+//!
+//! Sibling items of the same kind (same ordered-ness and marker) are merged
+//! into one shared `ListOrdered`/`ListUnordered` by
+//! [`document`][crate::content::document]: see
+//! `document::container_new_check_list_item`.
+//!
+//! > **Note**: loose vs. tight detection (wrapping item contents in `<p>`
+//! > when a blank line separates any items or their children) is not yet
+//! > wired up; see the note on `ContainerState` in
+//! > [`document`][crate::content::document].
+//!
+//! ## References
+//!
+//! *   [`list-item.js` in `micromark`](https://github.com/micromark/micromark/blob/main/packages/micromark-core-commonmark/dev/lib/list-item.js)
+//! *   [*§ 5.2 List items* in `CommonMark`](https://spec.commonmark.org/0.30/#list-items)
+//!
+//! [document]: crate::content::document
+//! [flow]: crate::content::flow
+//! [block_quote]: crate::construct::block_quote
+
+use crate::constant::TAB_SIZE;
+use crate::construct::partial_space_or_tab::space_or_tab_min_max;
+use crate::token::Token;
+use crate::tokenizer::{State, StateName, Tokenizer};
+
+/// The maximum number of spaces (or tabs) allowed before the marker.
+const LIST_ITEM_MARKER_INDENT_MAX: usize = 3;
+/// The maximum number of digits in an ordered list item's value.
+const LIST_ITEM_VALUE_SIZE_MAX: usize = 9;
+
+/// Start of list item.
+///
+/// ```markdown
+/// > | * a
+///     ^
+/// > | 1. b
+///     ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.constructs.list_item {
+        tokenizer.tokenize_state.list_item_start_column = tokenizer.point.column;
+        let state_name = space_or_tab_min_max(tokenizer, 0, LIST_ITEM_MARKER_INDENT_MAX);
+        tokenizer.attempt_opt(state_name, StateName::ListItemMarker)
+    } else {
+        State::Nok
+    }
+}
+
+/// At the marker.
+///
+/// ```markdown
+/// > | * a
+///     ^
+/// > | 1. b
+///     ^
+/// ```
+pub fn marker(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(byte @ (b'*' | b'+' | b'-')) => {
+            tokenizer.tokenize_state.list_item_ordered = false;
+            tokenizer.tokenize_state.list_item_marker = byte;
+            if !tokenizer.tokenize_state.document_list_item_sibling {
+                tokenizer.enter(Token::ListUnordered);
+            }
+            tokenizer.enter(Token::ListItem);
+            tokenizer.enter(Token::ListItemPrefix);
+            tokenizer.enter(Token::ListItemMarker);
+            tokenizer.consume();
+            tokenizer.exit(Token::ListItemMarker);
+            State::Fn(StateName::ListItemMarkerAfter)
+        }
+        Some(b'0'..=b'9') => {
+            tokenizer.tokenize_state.list_item_ordered = true;
+            if !tokenizer.tokenize_state.document_list_item_sibling {
+                tokenizer.enter(Token::ListOrdered);
+            }
+            tokenizer.enter(Token::ListItem);
+            tokenizer.enter(Token::ListItemPrefix);
+            tokenizer.enter(Token::ListItemMarker);
+            value(tokenizer)
+        }
+        _ => State::Nok,
+    }
+}
+
+/// In the value of an ordered list item marker.
+///
+/// ```markdown
+/// > | 1. b
+///     ^
+/// ```
+fn value(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(byte @ (b'.' | b')')) if tokenizer.tokenize_state.size > 0 => {
+            tokenizer.tokenize_state.size = 0;
+            tokenizer.tokenize_state.list_item_marker = byte;
+            tokenizer.consume();
+            tokenizer.exit(Token::ListItemMarker);
+            State::Fn(StateName::ListItemMarkerAfter)
+        }
+        Some(b'0'..=b'9') if tokenizer.tokenize_state.size < LIST_ITEM_VALUE_SIZE_MAX => {
+            tokenizer.tokenize_state.size += 1;
+            tokenizer.consume();
+            State::Fn(StateName::ListItemValue)
+        }
+        _ => {
+            tokenizer.tokenize_state.size = 0;
+            State::Nok
+        }
+    }
+}
+
+/// After the marker, before optional whitespace.
+///
+/// ```markdown
+/// > | * a
+///      ^
+/// ```
+pub fn marker_after(tokenizer: &mut Tokenizer) -> State {
+    let state_name = space_or_tab_min_max(tokenizer, 1, TAB_SIZE);
+    tokenizer.attempt(state_name, |ok| {
+        State::Fn(if ok {
+            StateName::ListItemPrefixEnd
+        } else {
+            StateName::ListItemWhitespaceMissing
+        })
+    })
+}
+
+/// After the marker, where there was no whitespace.
+///
+/// A blank line right after the marker (`-` followed by nothing else on the
+/// line) still forms a valid, empty, item: its content starts one column
+/// past the marker.
+///
+/// ```markdown
+/// > | -
+///      ^
+/// ```
+pub fn whitespace_missing(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => prefix_end(tokenizer),
+        _ => State::Nok,
+    }
+}
+
+/// After the list item prefix.
+///
+/// ```markdown
+/// > | * a
+///       ^
+/// ```
+pub fn prefix_end(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.exit(Token::ListItemPrefix);
+    tokenizer.tokenize_state.list_item_size =
+        tokenizer.point.column - tokenizer.tokenize_state.list_item_start_column;
+    State::Ok
+}
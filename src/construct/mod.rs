@@ -0,0 +1,18 @@
+//! Constructs found in markdown.
+//!
+//! Each construct has a `start`, and often also other exported functions,
+//! that are used as [`StateName`][crate::tokenizer::StateName] targets by
+//! the content types that use them ([document][], [flow][], [text][], and
+//! [string][]).
+//!
+//! [document]: crate::content::document
+//! [flow]: crate::content::flow
+//! [text]: crate::content::text
+//! [string]: crate::content::string
+
+pub mod block_quote;
+pub mod code_indented;
+pub mod list_item;
+pub mod partial_space_or_tab;
+pub mod partial_title;
+pub mod partial_whitespace;
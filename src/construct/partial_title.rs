@@ -28,14 +28,23 @@
 //! [string]: crate::content::string
 //! [character_escape]: crate::construct::character_escape
 //! [character_reference]: crate::construct::character_reference
-//!
-//! <!-- To do: link label end. -->
-
-// To do: pass token types in.
 
 use crate::construct::partial_whitespace::start as whitespace;
 use crate::tokenizer::{Code, State, StateFnResult, TokenType, Tokenizer};
 
+/// Configuration.
+///
+/// You must pass the token types in that are used.
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Token for the whole title.
+    pub title: TokenType,
+    /// Token for the marker.
+    pub marker: TokenType,
+    /// Token for the string inside the marker.
+    pub string: TokenType,
+}
+
 /// Type of title.
 #[derive(Debug, Clone, PartialEq)]
 enum Kind {
@@ -47,6 +56,15 @@ enum Kind {
     Single,
 }
 
+/// State needed to parse titles.
+#[derive(Debug, Clone)]
+struct Info {
+    /// Kind of title.
+    kind: Kind,
+    /// Configuration.
+    options: Options,
+}
+
 /// Display a marker.
 fn kind_to_marker(kind: &Kind) -> char {
     match kind {
@@ -63,7 +81,7 @@ fn kind_to_marker(kind: &Kind) -> char {
 /// |'a'
 /// |(a)
 /// ```
-pub fn start(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+pub fn start(tokenizer: &mut Tokenizer, code: Code, options: Options) -> StateFnResult {
     let kind = match code {
         Code::Char('"') => Some(Kind::Double),
         Code::Char('\'') => Some(Kind::Single),
@@ -72,11 +90,12 @@ pub fn start(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
     };
 
     if let Some(kind) = kind {
-        tokenizer.enter(TokenType::DefinitionTitle);
-        tokenizer.enter(TokenType::DefinitionTitleMarker);
+        let info = Info { kind, options };
+        tokenizer.enter(info.options.title.clone());
+        tokenizer.enter(info.options.marker.clone());
         tokenizer.consume(code);
-        tokenizer.exit(TokenType::DefinitionTitleMarker);
-        (State::Fn(Box::new(|t, c| begin(t, c, kind))), None)
+        tokenizer.exit(info.options.marker.clone());
+        (State::Fn(Box::new(|t, c| begin(t, c, info))), None)
     } else {
         (State::Nok, None)
     }
@@ -91,18 +110,18 @@ pub fn start(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
 /// '|a'
 /// (|a)
 /// ```
-fn begin(tokenizer: &mut Tokenizer, code: Code, kind: Kind) -> StateFnResult {
+fn begin(tokenizer: &mut Tokenizer, code: Code, info: Info) -> StateFnResult {
     match code {
-        Code::Char(char) if char == kind_to_marker(&kind) => {
-            tokenizer.enter(TokenType::DefinitionTitleMarker);
+        Code::Char(char) if char == kind_to_marker(&info.kind) => {
+            tokenizer.enter(info.options.marker.clone());
             tokenizer.consume(code);
-            tokenizer.exit(TokenType::DefinitionTitleMarker);
-            tokenizer.exit(TokenType::DefinitionTitle);
+            tokenizer.exit(info.options.marker.clone());
+            tokenizer.exit(info.options.title.clone());
             (State::Ok, None)
         }
         _ => {
-            tokenizer.enter(TokenType::DefinitionTitleString);
-            at_break(tokenizer, code, kind)
+            tokenizer.enter(info.options.string.clone());
+            at_break(tokenizer, code, info)
         }
     }
 }
@@ -115,23 +134,23 @@ fn begin(tokenizer: &mut Tokenizer, code: Code, kind: Kind) -> StateFnResult {
 /// (a|
 /// b)
 /// ```
-fn at_break(tokenizer: &mut Tokenizer, code: Code, kind: Kind) -> StateFnResult {
+fn at_break(tokenizer: &mut Tokenizer, code: Code, info: Info) -> StateFnResult {
     match code {
-        Code::Char(char) if char == kind_to_marker(&kind) => {
-            tokenizer.exit(TokenType::DefinitionTitleString);
-            begin(tokenizer, code, kind)
+        Code::Char(char) if char == kind_to_marker(&info.kind) => {
+            tokenizer.exit(info.options.string.clone());
+            begin(tokenizer, code, info)
         }
         Code::None => (State::Nok, None),
         Code::CarriageReturnLineFeed | Code::Char('\r' | '\n') => {
             tokenizer.enter(TokenType::LineEnding);
             tokenizer.consume(code);
             tokenizer.exit(TokenType::LineEnding);
-            (State::Fn(Box::new(|t, c| line_start(t, c, kind))), None)
+            (State::Fn(Box::new(|t, c| line_start(t, c, info))), None)
         }
         _ => {
             // To do: link.
             tokenizer.enter(TokenType::ChunkString);
-            title(tokenizer, code, kind)
+            title(tokenizer, code, info)
         }
     }
 }
@@ -142,10 +161,10 @@ fn at_break(tokenizer: &mut Tokenizer, code: Code, kind: Kind) -> StateFnResult
 /// "a
 /// |b"
 /// ```
-fn line_start(tokenizer: &mut Tokenizer, code: Code, kind: Kind) -> StateFnResult {
+fn line_start(tokenizer: &mut Tokenizer, code: Code, info: Info) -> StateFnResult {
     tokenizer.attempt(
         |t, c| whitespace(t, c, TokenType::Whitespace),
-        |_ok| Box::new(|t, c| line_begin(t, c, kind)),
+        |_ok| Box::new(|t, c| line_begin(t, c, info)),
     )(tokenizer, code)
 }
 
@@ -155,11 +174,11 @@ fn line_start(tokenizer: &mut Tokenizer, code: Code, kind: Kind) -> StateFnResul
 /// "a
 /// |b"
 /// ```
-fn line_begin(tokenizer: &mut Tokenizer, code: Code, kind: Kind) -> StateFnResult {
+fn line_begin(tokenizer: &mut Tokenizer, code: Code, info: Info) -> StateFnResult {
     match code {
         // Blank line not allowed.
         Code::CarriageReturnLineFeed | Code::Char('\r' | '\n') => (State::Nok, None),
-        _ => at_break(tokenizer, code, kind),
+        _ => at_break(tokenizer, code, info),
     }
 }
 
@@ -168,23 +187,23 @@ fn line_begin(tokenizer: &mut Tokenizer, code: Code, kind: Kind) -> StateFnResul
 /// ```markdown
 /// "a|b"
 /// ```
-fn title(tokenizer: &mut Tokenizer, code: Code, kind: Kind) -> StateFnResult {
+fn title(tokenizer: &mut Tokenizer, code: Code, info: Info) -> StateFnResult {
     match code {
-        Code::Char(char) if char == kind_to_marker(&kind) => {
+        Code::Char(char) if char == kind_to_marker(&info.kind) => {
             tokenizer.exit(TokenType::ChunkString);
-            at_break(tokenizer, code, kind)
+            at_break(tokenizer, code, info)
         }
         Code::None | Code::CarriageReturnLineFeed | Code::Char('\r' | '\n') => {
             tokenizer.exit(TokenType::ChunkString);
-            at_break(tokenizer, code, kind)
+            at_break(tokenizer, code, info)
         }
         Code::Char('\\') => {
             tokenizer.consume(code);
-            (State::Fn(Box::new(|t, c| escape(t, c, kind))), None)
+            (State::Fn(Box::new(|t, c| escape(t, c, info))), None)
         }
         _ => {
             tokenizer.consume(code);
-            (State::Fn(Box::new(|t, c| title(t, c, kind))), None)
+            (State::Fn(Box::new(|t, c| title(t, c, info))), None)
         }
     }
 }
@@ -194,12 +213,12 @@ fn title(tokenizer: &mut Tokenizer, code: Code, kind: Kind) -> StateFnResult {
 /// ```markdown
 /// "a\|"b"
 /// ```
-fn escape(tokenizer: &mut Tokenizer, code: Code, kind: Kind) -> StateFnResult {
+fn escape(tokenizer: &mut Tokenizer, code: Code, info: Info) -> StateFnResult {
     match code {
-        Code::Char(char) if char == kind_to_marker(&kind) => {
+        Code::Char(char) if char == kind_to_marker(&info.kind) => {
             tokenizer.consume(code);
-            (State::Fn(Box::new(move |t, c| title(t, c, kind))), None)
+            (State::Fn(Box::new(move |t, c| title(t, c, info))), None)
         }
-        _ => title(tokenizer, code, kind),
+        _ => title(tokenizer, code, info),
     }
 }
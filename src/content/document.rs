@@ -0,0 +1,322 @@
+//! The document content type.
+//!
+//! **Document** is the highest level content type, and it defines the
+//! containers: [block quote][block_quote] and [list item][list_item].
+//! It delegates to the [flow][flow] content type for the remainder of each
+//! line.
+//!
+//! Containers are dealt with on a line by line basis: at the start of each
+//! line, every currently open container is asked whether it continues (its
+//! continuation prefix is present), in the order in which the containers
+//! were opened.
+//! The number of containers that continue defines the point up to which
+//! the line "belongs" to those containers; the rest of the line (which may
+//! be empty) is then handed to [flow][].
+//! If flow decides the line is a paragraph continuation, but not every open
+//! container matched its continuation prefix, the line is *lazy*: flow is
+//! told so (through [`Tokenizer::lazy`][]), which is exactly what stops
+//! [code (indented)][code_indented] from swallowing an unprefixed
+//! continuation line.
+//! Containers whose prefix did not match are then closed, innermost first,
+//! and new containers that start on the remainder of the line are opened.
+//! Opening a container always takes precedence over laziness: a line is
+//! only lazy when *no* new container opens on it at all (see
+//! [`flow_start`]). A list item that opens where a same-kind item (same
+//! ordered-ness and marker) just failed to continue is treated as that
+//! item's sibling, and reuses its `ListOrdered`/`ListUnordered` wrapper
+//! instead of starting a new list.
+//!
+//! ## References
+//!
+//! *   [`document.js` in `micromark`](https://github.com/micromark/micromark/blob/main/packages/micromark/dev/lib/document.js)
+//!
+//! [flow]: crate::content::flow
+//! [block_quote]: crate::construct::block_quote
+//! [list_item]: crate::construct::list_item
+//! [code_indented]: crate::construct::code_indented
+//! [`Tokenizer::lazy`]: crate::tokenizer::Tokenizer
+
+use crate::construct::partial_space_or_tab::space_or_tab_min_max;
+use crate::token::Token;
+use crate::tokenizer::{State, StateName, Tokenizer};
+
+/// A single open container on the document stack.
+///
+/// > **Note**: loose/tight list detection (CommonMark's rule that a list is
+/// > loose, and so wraps its items' children in `<p>`, when a blank line
+/// > separates any of its items or their children) is not yet tracked here;
+/// > see the note in [`list_item`][crate::construct::list_item].
+#[derive(Debug, Clone)]
+struct ContainerState {
+    /// Kind of container, and the data needed to match its continuation.
+    kind: Container,
+}
+
+/// Kind of container.
+#[derive(Debug, Clone, PartialEq)]
+enum Container {
+    /// Block quote: continues on `>`, optionally followed by one space.
+    BlockQuote,
+    /// List item: continues when the line is indented as far as its
+    /// content (`size` columns).
+    ListItem {
+        /// Whether the list is ordered (`1.`) or unordered (`-`).
+        ordered: bool,
+        /// The marker byte (`*`, `+`, `-`, `.`, or `)`): a new item only
+        /// joins the currently open list (sharing its `ListOrdered`/
+        /// `ListUnordered`) when this, and `ordered`, both match.
+        marker: u8,
+        /// Width, in columns, from the start of the item up to (and
+        /// including) the whitespace after its marker.
+        size: usize,
+    },
+}
+
+/// Start of the document content type.
+///
+/// Called once, for the first line; every following line is handled by
+/// [`flow_after`][], which loops back into `container_continue`.
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.document_continued = 0;
+    container_continue(tokenizer)
+}
+
+/// Try to continue the open container at `document_continued`.
+///
+/// ```markdown
+/// > | > a
+///     ^
+/// > | * b
+///     ^
+/// ```
+fn container_continue(tokenizer: &mut Tokenizer) -> State {
+    let index = tokenizer.tokenize_state.document_continued;
+
+    if index == tokenizer.tokenize_state.document_container_stack.len() {
+        // All currently open containers matched: this line is not lazy,
+        // even if an earlier line was (`tokenizer.lazy` is otherwise only
+        // ever set, never cleared, by `container_existing_after`).
+        tokenizer.lazy = false;
+        return container_new_before(tokenizer);
+    }
+
+    match tokenizer.tokenize_state.document_container_stack[index].kind {
+        Container::BlockQuote => tokenizer.attempt(StateName::BlockQuoteContinuation, |ok| {
+            State::Fn(if ok {
+                StateName::DocumentContainerContinued
+            } else {
+                StateName::DocumentContainerExistingAfter
+            })
+        }),
+        Container::ListItem { size, .. } => {
+            let state_name = space_or_tab_min_max(tokenizer, size, size);
+            tokenizer.attempt(state_name, |ok| {
+                State::Fn(if ok {
+                    StateName::DocumentContainerContinued
+                } else {
+                    StateName::DocumentContainerExistingAfter
+                })
+            })
+        }
+    }
+}
+
+/// A container continued: move on to the next one.
+pub fn container_continued(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.document_continued += 1;
+    container_continue(tokenizer)
+}
+
+/// A container did *not* continue.
+///
+/// If flow still treats the rest of the line as a paragraph continuation,
+/// this is a lazy line: flow is told so, and the unmatched containers are
+/// kept open for now.
+/// Otherwise they (and everything after them) are closed, innermost first,
+/// once flow for this line has run.
+pub fn container_existing_after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.lazy = tokenizer.tokenize_state.document_continued
+        < tokenizer.tokenize_state.document_container_stack.len();
+    container_new_before(tokenizer)
+}
+
+/// After continuing (or lazily keeping) existing containers: look for new
+/// containers opening on the remainder of the line.
+///
+/// Tried unconditionally, even on a line some containers failed to
+/// continue: a fresh `>` or list marker always takes precedence over
+/// laziness (only a line with *no* container marker at all, recognized by
+/// flow as a paragraph continuation, is truly lazy). See
+/// [`flow_start`][] for where `tokenizer.lazy` is actually consulted, once
+/// it is established that nothing new opens here.
+///
+/// ```markdown
+/// > | > * a
+///       ^
+/// ```
+pub fn container_new_before(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(StateName::BlockQuoteStart, |ok| {
+        State::Fn(if ok {
+            StateName::DocumentContainerNewAfterBlockQuote
+        } else {
+            StateName::DocumentContainerNewBeforeListItem
+        })
+    })
+}
+
+/// Try a list item after a block quote did not open.
+///
+/// First checks, without consuming, whether one opens here at all: if it
+/// does, and it is the same kind (ordered-ness and marker) as the container
+/// at `document_continued` that just failed to continue, the two are
+/// siblings in one list, and the new item should reuse its `ListOrdered`/
+/// `ListUnordered` wrapper instead of opening a new, separate, list.
+pub fn container_new_before_list_item(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.check(StateName::ListItemStart, |ok| {
+        State::Fn(if ok {
+            StateName::DocumentContainerNewCheckListItem
+        } else {
+            StateName::DocumentFlowStart
+        })
+    })
+}
+
+/// A list item could open here (checked, not yet committed): decide whether
+/// it joins the list at `document_continued` as a sibling, or starts a new
+/// one, then actually parse it.
+pub fn container_new_check_list_item(tokenizer: &mut Tokenizer) -> State {
+    let index = tokenizer.tokenize_state.document_continued;
+    let existing = tokenizer
+        .tokenize_state
+        .document_container_stack
+        .get(index)
+        .map(|container| container.kind.clone());
+    let sibling = match existing {
+        Some(Container::ListItem { ordered, marker, .. }) => {
+            ordered == tokenizer.tokenize_state.list_item_ordered
+                && marker == tokenizer.tokenize_state.list_item_marker
+        }
+        _ => false,
+    };
+
+    if sibling {
+        // Close only the old item: the list it is part of stays open for
+        // the new one to join. Containers nested inside the old item are
+        // closed first, innermost first.
+        close_containers_after(tokenizer, index + 1);
+        tokenizer.exit(Token::ListItem);
+    } else {
+        close_containers_after(tokenizer, index);
+    }
+
+    tokenizer.tokenize_state.document_list_item_sibling = sibling;
+    tokenizer.attempt(StateName::ListItemStart, |ok| {
+        State::Fn(if ok {
+            StateName::DocumentContainerNewAfterListItem
+        } else {
+            StateName::DocumentFlowStart
+        })
+    })
+}
+
+/// A new block quote opened: record it, and look for another, nested,
+/// container.
+pub fn container_new_after_block_quote(tokenizer: &mut Tokenizer) -> State {
+    tokenizer
+        .tokenize_state
+        .document_container_stack
+        .push(ContainerState {
+            kind: Container::BlockQuote,
+        });
+    tokenizer.tokenize_state.document_continued += 1;
+    // A fresh container just opened: this line is conclusively not a lazy
+    // paragraph continuation.
+    tokenizer.lazy = false;
+    container_new_before(tokenizer)
+}
+
+/// A new list item opened: record it (joining the sibling list found by
+/// [`container_new_check_list_item`][], if any), and look for another,
+/// nested, container.
+pub fn container_new_after_list_item(tokenizer: &mut Tokenizer) -> State {
+    let ordered = tokenizer.tokenize_state.list_item_ordered;
+    let marker = tokenizer.tokenize_state.list_item_marker;
+    let size = tokenizer.tokenize_state.list_item_size;
+    tokenizer.tokenize_state.list_item_size = 0;
+    let kind = Container::ListItem {
+        ordered,
+        marker,
+        size,
+    };
+
+    if tokenizer.tokenize_state.document_list_item_sibling {
+        let index = tokenizer.tokenize_state.document_continued;
+        tokenizer.tokenize_state.document_container_stack[index].kind = kind;
+    } else {
+        tokenizer
+            .tokenize_state
+            .document_container_stack
+            .push(ContainerState { kind });
+    }
+
+    tokenizer.tokenize_state.document_list_item_sibling = false;
+    tokenizer.tokenize_state.document_continued += 1;
+    // A fresh (or continuing sibling) item just opened: this line is
+    // conclusively not a lazy paragraph continuation.
+    tokenizer.lazy = false;
+    container_new_before(tokenizer)
+}
+
+/// No more containers open on this line: close the ones that were not
+/// continued, then hand the remainder of the line to flow.
+///
+/// A lazy line (see [`container_existing_after`][]) does *not* close its
+/// unmatched containers here: flow hasn't even told us yet whether it will
+/// treat the line as a paragraph continuation, and the containers may still
+/// have unclosed flow constructs (such as a `Paragraph`) open above them on
+/// the event stack, so exiting them now would produce an invalid,
+/// non-LIFO event order. They stay open until a following non-lazy line
+/// either continues or properly closes them.
+pub fn flow_start(tokenizer: &mut Tokenizer) -> State {
+    let keep = if tokenizer.lazy {
+        tokenizer.tokenize_state.document_container_stack.len()
+    } else {
+        tokenizer.tokenize_state.document_continued
+    };
+    close_containers_after(tokenizer, keep);
+    tokenizer.go(StateName::FlowStart, StateName::DocumentFlowAfter)
+}
+
+/// After flow ran for the (remaining part of the) line: loop for the next
+/// line, or stop at the end of the document.
+pub fn flow_after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current.is_none() {
+        close_containers_after(tokenizer, 0);
+        State::Ok
+    } else {
+        tokenizer.tokenize_state.document_continued = 0;
+        container_continue(tokenizer)
+    }
+}
+
+/// Pop and exit containers down to (but not including) `keep` entries.
+fn close_containers_after(tokenizer: &mut Tokenizer, keep: usize) {
+    while tokenizer.tokenize_state.document_container_stack.len() > keep {
+        let container = tokenizer
+            .tokenize_state
+            .document_container_stack
+            .pop()
+            .expect("just checked length");
+        match container.kind {
+            Container::BlockQuote => tokenizer.exit(Token::BlockQuote),
+            Container::ListItem { ordered, .. } => {
+                tokenizer.exit(Token::ListItem);
+                tokenizer.exit(if ordered {
+                    Token::ListOrdered
+                } else {
+                    Token::ListUnordered
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! Content types found in markdown.
+//!
+//! *   [document][document] — block quotes, list items, and everything else
+//! *   [flow][flow] — block constructs, such as code, headings, and thematic
+//!     breaks
+//! *   [string][string] — a limited text-like content type
+//! *   [text][text] — a text-like content type
+//!
+//! [document]: crate::content::document
+//! [flow]: crate::content::flow
+//! [string]: crate::content::string
+//! [text]: crate::content::text
+
+pub mod document;
+pub mod flow;
+pub mod string;
+pub mod text;
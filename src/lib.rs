@@ -0,0 +1,262 @@
+//! Convert markdown to HTML.
+//!
+//! *   [`micromark`][] — safe, default, CommonMark-compliant
+//! *   [`micromark_with_options`][] — lets you tweak behavior with [`Options`][]
+//!
+//! [`micromark`]: crate::micromark
+//! [`micromark_with_options`]: crate::micromark_with_options
+
+pub mod compiler;
+mod construct;
+mod content;
+mod parser;
+mod token;
+mod tokenizer;
+pub mod util;
+
+use std::fmt;
+
+use crate::parser::parse;
+use crate::tokenizer::compile;
+
+/// Turn markdown into HTML, with the default [`Options`][] (CommonMark).
+///
+/// ```
+/// use micromark::micromark;
+///
+/// assert_eq!(micromark("# hi"), "<h1>hi</h1>");
+/// ```
+pub fn micromark(value: &str) -> String {
+    micromark_with_options(value, &Options::default())
+}
+
+/// Turn markdown into HTML, with configuration.
+///
+/// ```
+/// use micromark::{micromark_with_options, Constructs, Options};
+///
+/// let result = micromark_with_options(
+///     "   foo",
+///     &Options {
+///         constructs: Constructs {
+///             code_indented: false,
+///             ..Constructs::default()
+///         },
+///         ..Options::default()
+///     },
+/// );
+///
+/// assert_eq!(result, "<p>foo</p>");
+/// ```
+pub fn micromark_with_options(value: &str, options: &Options) -> String {
+    let (events, bytes) = parse(value, options);
+    compile(&events, &bytes, options)
+}
+
+/// Configuration that describes how to parse and compile markdown.
+///
+/// Mirrors `micromark`'s `ParseOptions`/`CompileOptions`, combined, as the
+/// Rust port does not need the split: everything is known up front.
+///
+/// Does not implement `Clone`, as [`Options::code_block`][] may hold a
+/// closure.
+pub struct Options {
+    /// Which constructs to enable and disable.
+    ///
+    /// By default all constructs are on. Only CommonMark constructs exist,
+    /// so [`Constructs::commonmark`][] and [`Constructs::gfm`][] give the
+    /// same set as the default; flip individual fields on a preset to pick
+    /// and choose.
+    pub constructs: Constructs,
+    /// Shift every heading by this many levels, clamping at `<h6>`.
+    ///
+    /// A heading offset of `1` turns an ATX/setext `#` into `<h2>` instead
+    /// of `<h1>`, which is useful when embedding a document (such as a
+    /// rustdoc-style page) under a heading of its own. Applied by
+    /// [`compiler::heading_tags`][crate::compiler::heading_tags].
+    pub heading_offset: u8,
+    /// Generate a GitHub-style `id` attribute for every heading, using
+    /// [`GithubSlugger`][crate::util::slugger::GithubSlugger]. Applied by
+    /// [`compiler::heading_tags`][crate::compiler::heading_tags].
+    ///
+    /// Collisions across the document are disambiguated by appending `-1`,
+    /// `-2`, and so on.
+    pub heading_ids: bool,
+    /// Hook invoked whenever the compiler renders a fenced or indented code
+    /// block, modeled on how `rustdoc` rewrites code blocks (language
+    /// classes, playground links, syntax highlighting). Applied by
+    /// [`compiler::code_block_html`][crate::compiler::code_block_html].
+    ///
+    /// Receives the block's info string (the part of a fenced block's
+    /// opening line after the fence, or empty for indented code blocks) and
+    /// its raw text, and returns the markup to use instead. Leaving this as
+    /// `None` keeps the default, CommonMark-compliant
+    /// `<pre><code class="language-…">` output.
+    pub code_block: Option<Box<CodeBlockHook>>,
+}
+
+impl Default for Options {
+    /// Use all constructs, no heading offset or ids, and the default
+    /// (CommonMark-compliant) code block rendering.
+    fn default() -> Self {
+        Self {
+            constructs: Constructs::default(),
+            heading_offset: 0,
+            heading_ids: false,
+            code_block: None,
+        }
+    }
+}
+
+impl fmt::Debug for Options {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Options")
+            .field("constructs", &self.constructs)
+            .field("heading_offset", &self.heading_offset)
+            .field("heading_ids", &self.heading_ids)
+            .field("code_block", &self.code_block.as_ref().map(|_| "Fn(..)"))
+            .finish()
+    }
+}
+
+/// The info given to, and the markup returned from, a
+/// [`code_block`][Options::code_block] hook.
+pub type CodeBlockHook = dyn Fn(CodeBlockInfo) -> CodeBlockRender;
+
+/// Input passed to a [`CodeBlockHook`][].
+#[derive(Clone, Copy, Debug)]
+pub struct CodeBlockInfo<'a> {
+    /// Info string: the language and any metadata after a fenced block's
+    /// opening fence, or the empty string for indented code blocks (which
+    /// have no info string).
+    pub info: &'a str,
+    /// The code itself, with the block's indentation or fence already
+    /// stripped, and without a trailing line ending.
+    pub value: &'a str,
+}
+
+/// What a [`CodeBlockHook`][] renders instead of the default markup.
+#[derive(Clone, Debug, Default)]
+pub struct CodeBlockRender {
+    /// Inner HTML to place between `<code>` and `</code>` (the hook is
+    /// responsible for escaping it).
+    pub html: String,
+    /// Extra, already-formatted attributes (for example
+    /// `" data-line-numbers"`) to add to the `<code>` element.
+    pub attributes: String,
+}
+
+/// Configuration of what constructs are enabled.
+///
+/// Not all constructs can be configured: ones that are required to make
+/// sense of the document, such as line endings and whitespace, are always
+/// on.
+///
+/// ```
+/// use micromark::Constructs;
+///
+/// // Default (everything on):
+/// let commonmark_and_gfm = Constructs::default();
+///
+/// // Just CommonMark:
+/// let commonmark = Constructs::commonmark();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Constructs {
+    /// Code (indented).
+    ///
+    /// ```markdown
+    /// > |     a
+    ///     ^^^^
+    /// ```
+    pub code_indented: bool,
+    /// Code (fenced).
+    ///
+    /// ```markdown
+    /// > | ```js
+    ///     ^^^^^
+    /// > | a
+    ///     ^
+    /// > | ```
+    ///     ^^^
+    /// ```
+    pub code_fenced: bool,
+    /// Heading (atx).
+    ///
+    /// ```markdown
+    /// > | # a
+    ///     ^^^
+    /// ```
+    pub heading_atx: bool,
+    /// Heading (setext).
+    ///
+    /// ```markdown
+    /// > | a
+    ///     ^
+    /// > | =
+    ///     ^
+    /// ```
+    pub heading_setext: bool,
+    /// Thematic break.
+    ///
+    /// ```markdown
+    /// > | ***
+    ///     ^^^
+    /// ```
+    pub thematic_break: bool,
+    /// Definition.
+    ///
+    /// ```markdown
+    /// > | [a]: b
+    ///     ^^^^^^
+    /// ```
+    pub definition: bool,
+    /// Block quote.
+    ///
+    /// ```markdown
+    /// > | > a
+    ///     ^^^
+    /// ```
+    pub block_quote: bool,
+    /// List item.
+    ///
+    /// ```markdown
+    /// > | * a
+    ///     ^^^
+    /// ```
+    pub list_item: bool,
+}
+
+impl Default for Constructs {
+    /// Use all constructs.
+    fn default() -> Self {
+        Self {
+            code_indented: true,
+            code_fenced: true,
+            heading_atx: true,
+            heading_setext: true,
+            thematic_break: true,
+            definition: true,
+            block_quote: true,
+            list_item: true,
+        }
+    }
+}
+
+impl Constructs {
+    /// Use all constructs.
+    ///
+    /// Currently equivalent to [`Constructs::default`][], as this crate does
+    /// not yet implement any construct beyond CommonMark.
+    pub fn gfm() -> Self {
+        Self::default()
+    }
+
+    /// Use only the constructs that are required to parse CommonMark.
+    ///
+    /// Currently equivalent to [`Constructs::default`][], as this crate does
+    /// not yet implement any construct beyond CommonMark.
+    pub fn commonmark() -> Self {
+        Self::default()
+    }
+}
@@ -0,0 +1,3 @@
+//! Utilities shared across the crate.
+
+pub mod slugger;
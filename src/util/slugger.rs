@@ -0,0 +1,81 @@
+//! A small, [`github-slugger`][github-slugger]-compatible slug generator.
+//!
+//! Used by the HTML compiler to generate stable `id` attributes for
+//! headings (see [`Options::heading_ids`][crate::Options::heading_ids]).
+//!
+//! [github-slugger]: https://github.com/Flet/github-slugger
+
+use std::collections::HashMap;
+
+/// Generates unique, GitHub-style slugs for a sequence of strings.
+///
+/// Each instance keeps track of the slugs it already handed out, so that
+/// calling [`slug`][GithubSlugger::slug] with the same (or normalizing to
+/// the same) value twice returns `foo`, then `foo-1`, then `foo-2`, and so
+/// on.
+///
+/// ```
+/// use micromark::util::slugger::GithubSlugger;
+///
+/// let mut slugger = GithubSlugger::new();
+/// assert_eq!(slugger.slug("Hello, World!"), "hello-world");
+/// assert_eq!(slugger.slug("Hello, World!"), "hello-world-1");
+///
+/// // A heading with no sluggable characters falls back to a fixed base,
+/// // rather than producing a confusing, empty (or `-1`, `-2`, …) id.
+/// assert_eq!(slugger.slug("!!!"), "section");
+/// assert_eq!(slugger.slug("!!!"), "section-1");
+/// ```
+#[derive(Debug, Default)]
+pub struct GithubSlugger {
+    /// Slugs seen so far, and how many times.
+    seen: HashMap<String, usize>,
+}
+
+impl GithubSlugger {
+    /// Create an empty slugger, with no slugs seen yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Turn `value` into a unique slug, remembering it for future calls.
+    pub fn slug(&mut self, value: &str) -> String {
+        let base = normalize(value);
+        // A heading made up entirely of stripped characters (punctuation
+        // other than `-`, or nothing at all) normalizes to the empty string,
+        // which would otherwise produce the
+        // nonsensical id `""` followed by `"-1"`; fall back to a fixed base
+        // instead, matching `github-slugger`'s own behavior.
+        let base = if base.is_empty() {
+            "section".into()
+        } else {
+            base
+        };
+        let mut slug = base.clone();
+        // Keep suffixing until the slug is one not handed out before (a
+        // heading can itself be, say, `foo-1`).
+        while self.seen.contains_key(&slug) {
+            let count = self.seen.get_mut(&base).expect("`base` was seen");
+            slug = format!("{}-{}", base, count);
+            *count += 1;
+        }
+        self.seen.insert(slug.clone(), 1);
+        slug
+    }
+}
+
+/// Lowercase `value`, drop everything but alphanumerics, spaces, and
+/// hyphens, and collapse runs of whitespace into single hyphens.
+fn normalize(value: &str) -> String {
+    let mut cleaned = String::with_capacity(value.len());
+
+    for lower in value.chars().flat_map(char::to_lowercase) {
+        if lower.is_alphanumeric() || lower == '-' {
+            cleaned.push(lower);
+        } else if lower.is_whitespace() {
+            cleaned.push(' ');
+        }
+    }
+
+    cleaned.split_whitespace().collect::<Vec<_>>().join("-")
+}
@@ -0,0 +1,35 @@
+extern crate micromark;
+use micromark::micromark;
+
+#[test]
+fn block_quote() {
+    assert_eq!(
+        micromark("> a"),
+        "<blockquote>\n<p>a</p>\n</blockquote>",
+        "should support a block quote"
+    );
+
+    assert_eq!(
+        micromark("> a\n> b"),
+        "<blockquote>\n<p>a\nb</p>\n</blockquote>",
+        "should support a block quote that continues on a prefixed line"
+    );
+
+    assert_eq!(
+        micromark("> a\nb\n> c"),
+        "<blockquote>\n<p>a\nb\nc</p>\n</blockquote>",
+        "should support a lazy line in the middle of a block quote (CommonMark example 228)"
+    );
+
+    assert_eq!(
+        micromark("> > a"),
+        "<blockquote>\n<blockquote>\n<p>a</p>\n</blockquote>\n</blockquote>",
+        "should support a nested block quote"
+    );
+
+    assert_eq!(
+        micromark("> a\nb\n> > c"),
+        "<blockquote>\n<p>a\nb</p>\n<blockquote>\n<p>c</p>\n</blockquote>\n</blockquote>",
+        "should be able to open a new, nested, block quote right after a lazy line"
+    );
+}
@@ -0,0 +1,20 @@
+extern crate micromark;
+use micromark::{micromark_with_options, CodeBlockRender, Options};
+
+#[test]
+fn code_block_hook() {
+    assert_eq!(
+        micromark_with_options(
+            "```rs\na\n```",
+            &Options {
+                code_block: Some(Box::new(|info| CodeBlockRender {
+                    html: format!("<mark>{}</mark>", info.value),
+                    attributes: format!(" data-lang=\"{}\"", info.info),
+                })),
+                ..Options::default()
+            }
+        ),
+        "<pre><code data-lang=\"rs\"><mark>a</mark></code></pre>",
+        "should let a code_block hook override fenced code block rendering"
+    );
+}
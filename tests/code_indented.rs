@@ -1,5 +1,5 @@
 extern crate micromark;
-use micromark::micromark;
+use micromark::{micromark, micromark_with_options, Constructs, Options};
 
 #[test]
 fn code_indented() {
@@ -76,56 +76,67 @@ fn code_indented() {
         "should support trailing whitespace"
     );
 
-    // To do: blockquote.
-    //     assert_eq!(
-    //         micromark(">     a\nb"),
-    //         "<blockquote>\n<pre><code>a\n</code></pre>\n</blockquote>\n<p>b</p>",
-    //         "should not support lazyness (1)"
-    //     );
+    // The tokenizer now understands block quotes (see `content::document`)
+    // and sets `Tokenizer::lazy` correctly, so these can be asserted end to
+    // end.
+    assert_eq!(
+        micromark(">     a\nb"),
+        "<blockquote>\n<pre><code>a\n</code></pre>\n</blockquote>\n<p>b</p>",
+        "should not support lazyness (1)"
+    );
 
-    //     assert_eq!(
-    //         micromark("> a\n    b"),
-    //         "<blockquote>\n<p>a\nb</p>\n</blockquote>",
-    //         "should not support lazyness (2)"
-    //     );
+    assert_eq!(
+        micromark("> a\n    b"),
+        "<blockquote>\n<p>a\nb</p>\n</blockquote>",
+        "should not support lazyness (2)"
+    );
 
-    //     assert_eq!(
-    //         micromark("> a\n     b"),
-    //         "<blockquote>\n<p>a\nb</p>\n</blockquote>",
-    //         "should not support lazyness (3)"
-    //     );
+    assert_eq!(
+        micromark("> a\n     b"),
+        "<blockquote>\n<p>a\nb</p>\n</blockquote>",
+        "should not support lazyness (3)"
+    );
 
-    //     assert_eq!(
-    //         micromark("> a\n      b"),
-    //         "<blockquote>\n<p>a\nb</p>\n</blockquote>",
-    //         "should not support lazyness (4)"
-    //     );
+    assert_eq!(
+        micromark("> a\n      b"),
+        "<blockquote>\n<p>a\nb</p>\n</blockquote>",
+        "should not support lazyness (4)"
+    );
 
-    //     assert_eq!(
-    //         micromark(">     a\n    b"),
-    //         "<blockquote>\n<pre><code>a\n</code></pre>\n</blockquote>\n<pre><code>b\n</code></pre>",
-    //         "should not support lazyness (5)"
-    //     );
+    assert_eq!(
+        micromark(">     a\n    b"),
+        "<blockquote>\n<pre><code>a\n</code></pre>\n</blockquote>\n<pre><code>b\n</code></pre>",
+        "should not support lazyness (5)"
+    );
 
-    //     assert_eq!(
-    //         micromark(">     a\n     b"),
-    //         "<blockquote>\n<pre><code>a\n</code></pre>\n</blockquote>\n<pre><code> b\n</code></pre>",
-    //         "should not support lazyness (6)"
-    //     );
+    assert_eq!(
+        micromark(">     a\n     b"),
+        "<blockquote>\n<pre><code>a\n</code></pre>\n</blockquote>\n<pre><code> b\n</code></pre>",
+        "should not support lazyness (6)"
+    );
 
-    //     assert_eq!(
-    //         micromark(">     a\n      b"),
-    //         "<blockquote>\n<pre><code>a\n</code></pre>\n</blockquote>\n<pre><code>  b\n</code></pre>",
-    //         "should not support lazyness (7)"
-    //     );
+    assert_eq!(
+        micromark(">     a\n      b"),
+        "<blockquote>\n<pre><code>a\n</code></pre>\n</blockquote>\n<pre><code>  b\n</code></pre>",
+        "should not support lazyness (7)"
+    );
 
-    // To do: extensions.
-    // assert_eq!(
-    //   micromark("   a", {extensions: [{disable: {null: ["codeIndented"]}}]}),
-    //   "<p>a</p>",
-    //   "should support turning off code (indented, 1)"
-    // );
+    assert_eq!(
+        micromark_with_options(
+            "   a",
+            &Options {
+                constructs: Constructs {
+                    code_indented: false,
+                    ..Constructs::default()
+                },
+                ..Options::default()
+            }
+        ),
+        "<p>a</p>",
+        "should support turning off code (indented, 1)"
+    );
 
+    // To do: containers.
     // assert_eq!(
     //   micromark("> a\n    b", {
     //     extensions: [{disable: {null: ["codeIndented"]}}]
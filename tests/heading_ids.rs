@@ -0,0 +1,29 @@
+extern crate micromark;
+use micromark::{micromark_with_options, Options};
+
+#[test]
+fn heading_offset_and_ids() {
+    assert_eq!(
+        micromark_with_options(
+            "# a\n\n## a",
+            &Options {
+                heading_ids: true,
+                ..Options::default()
+            }
+        ),
+        "<h1 id=\"a\">a</h1>\n<h2 id=\"a-1\">a</h2>",
+        "should add ids to headings, deduplicating collisions across the document"
+    );
+
+    assert_eq!(
+        micromark_with_options(
+            "# a",
+            &Options {
+                heading_offset: 2,
+                ..Options::default()
+            }
+        ),
+        "<h3>a</h3>",
+        "should shift heading levels by heading_offset"
+    );
+}
@@ -0,0 +1,35 @@
+extern crate micromark;
+use micromark::micromark;
+
+#[test]
+fn list_item() {
+    assert_eq!(
+        micromark("- a"),
+        "<ul>\n<li>a</li>\n</ul>",
+        "should support a list item"
+    );
+
+    assert_eq!(
+        micromark("- a\n- b"),
+        "<ul>\n<li>a</li>\n<li>b</li>\n</ul>",
+        "should merge sibling items with the same marker into one list"
+    );
+
+    assert_eq!(
+        micromark("1. a\n2. b"),
+        "<ol>\n<li>a</li>\n<li>b</li>\n</ol>",
+        "should merge sibling ordered items into one list"
+    );
+
+    assert_eq!(
+        micromark("- a\n* b"),
+        "<ul>\n<li>a</li>\n</ul>\n<ul>\n<li>b</li>\n</ul>",
+        "should start a new list when the bullet marker changes"
+    );
+
+    assert_eq!(
+        micromark("- a\n  - b"),
+        "<ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n</li>\n</ul>",
+        "should support a nested list item"
+    );
+}